@@ -1,11 +1,251 @@
 use std::fmt;
 use rand::prelude::*;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// The sentinel value used to represent the American "00" pocket inside a `u8`.
+/// Chosen as 37 so it sorts after every regular number (0-36) in the ascending
+/// arrays the bet validation relies on.
+pub const DOUBLE_ZERO: u8 = 37;
+
+/// Which physical wheel layout a table is using. European wheels have a single
+/// zero (37 pockets); American wheels add the "00" pocket (38 pockets).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WheelKind {
+    European,
+    American,
+}
+
+impl WheelKind {
+    /// Total number of pockets on the wheel, including zero(es).
+    pub fn pocket_count(&self) -> u8 {
+        match self {
+            WheelKind::European => 37,
+            WheelKind::American => 38,
+        }
+    }
+
+    /// The physical (not numeric) pocket order around the wheel, as the ball would pass
+    /// them. Used for announced/neighbour bets, which only make sense in wheel order.
+    pub fn pocket_order(&self) -> &'static [u8] {
+        match self {
+            WheelKind::European => &EUROPEAN_WHEEL_ORDER,
+            WheelKind::American => &AMERICAN_WHEEL_ORDER,
+        }
+    }
+
+    /// The numbers that are `each_side` pockets away from `center` on either side, plus
+    /// `center` itself, following the physical wheel order and wrapping around.
+    pub fn neighbors(&self, center: u8, each_side: u8) -> Vec<u8> {
+        let order = self.pocket_order();
+        let len = order.len();
+        let center_index = match order.iter().position(|&n| n == center) {
+            Some(index) => index,
+            None => return Vec::new(),
+        };
+
+        let mut numbers = Vec::new();
+        for offset in 0..=each_side as usize {
+            let right = (center_index + offset) % len;
+            let left = (center_index + len - offset % len) % len;
+            if !numbers.contains(&order[right]) {
+                numbers.push(order[right]);
+            }
+            if !numbers.contains(&order[left]) {
+                numbers.push(order[left]);
+            }
+        }
+        numbers
+    }
+}
+
+/// The 3-column, 12-row betting grid that numbers 1-36 are laid out on (zero sits in its
+/// own row above it). Bet adjacency (splits, corners, streets) is derived from this
+/// layout instead of hard-coded number tuples, so any future layout can reuse it.
+pub struct BettingGrid;
+
+impl BettingGrid {
+    const COLUMNS: u8 = 3;
+    const ROWS: u8 = 12;
+
+    /// The zero-indexed (row, column) of `number` on the grid, or `None` for zero/out-of-range.
+    fn position(number: u8) -> Option<(u8, u8)> {
+        if number == 0 || number > 36 {
+            return None;
+        }
+        let index = number - 1;
+        Some((index / Self::COLUMNS, index % Self::COLUMNS))
+    }
+
+    /// Whether `a` and `b` form a legal split: either zero paired with 1, 2, or 3, or two
+    /// distinct numbers occupying vertically or horizontally neighbouring cells.
+    pub fn is_adjacent_split(a: u8, b: u8) -> bool {
+        if a == b {
+            return false;
+        }
+        if a == 0 || b == 0 {
+            let other = if a == 0 { b } else { a };
+            return other == 1 || other == 2 || other == 3;
+        }
+
+        match (Self::position(a), Self::position(b)) {
+            (Some((row_a, col_a)), Some((row_b, col_b))) => {
+                (row_a == row_b && (col_a as i16 - col_b as i16).abs() == 1) ||
+                (col_a == col_b && (row_a as i16 - row_b as i16).abs() == 1)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether `[n, n+1, n+3, n+4]` is a legal 2x2 corner block: `n` needs a cell to its
+    /// right and a row below it for the block to stay on the grid.
+    pub fn is_valid_corner(v: [u8; 4]) -> bool {
+        match Self::position(v[0]) {
+            Some((row, col)) => {
+                col < Self::COLUMNS - 1 && row < Self::ROWS - 1 && v == [v[0], v[0] + 1, v[0] + 3, v[0] + 4]
+            }
+            None => false,
+        }
+    }
+
+    /// Whether `[n, n+1, n+2]` is a legal street: a full row of the grid, starting at
+    /// its leftmost column.
+    pub fn is_valid_street(v: [u8; 3]) -> bool {
+        match Self::position(v[0]) {
+            Some((_, col)) => col == 0 && v == [v[0], v[0] + 1, v[0] + 2],
+            None => false,
+        }
+    }
+}
+
+/// European (single-zero) wheel pocket order, as the ball would encounter them going
+/// around the physical wheel.
+pub const EUROPEAN_WHEEL_ORDER: [u8; 37] = [
+    0, 32, 15, 19, 4, 21, 2, 25, 17, 34, 6, 27, 13, 36, 11, 30, 8, 23, 10, 5, 24, 16, 33, 1, 20, 14, 31, 9, 22, 18, 29, 7, 28, 12, 35, 3, 26,
+];
+
+/// American (double-zero) wheel pocket order. `DOUBLE_ZERO` stands in for "00".
+pub const AMERICAN_WHEEL_ORDER: [u8; 38] = [
+    0, 28, 9, 26, 30, 11, 7, 20, 32, 17, 5, 22, 34, 15, 3, 24, 36, 13, 1, DOUBLE_ZERO, 27, 10, 25, 29, 12, 8, 19, 31, 18, 6, 21, 33, 16, 4, 23, 35, 14, 2,
+];
+
+/// The classic French announced-bet sectors, expressed as the European wheel-order
+/// number sets they cover.
+pub const VOISINS_DU_ZERO: [u8; 17] = [22, 18, 29, 7, 28, 12, 35, 3, 26, 0, 32, 15, 19, 4, 21, 2, 25];
+pub const TIERS_DU_CYLINDRE: [u8; 12] = [27, 13, 36, 11, 30, 8, 23, 10, 5, 24, 16, 33];
+pub const ORPHELINS: [u8; 8] = [17, 34, 6, 1, 20, 14, 31, 9];
+
+/// A named French "call bet" (announced bet), covering a fixed sector of the European
+/// wheel with a standard set of inside bets. `to_bets` expands the announced bet into
+/// the canonical `RouletteBetType`s a player would actually place, so callers don't have
+/// to reconstruct the split/corner tuples themselves.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CallBet {
+    /// "Neighbours of zero": the 17 numbers either side of 0 on the wheel, covered by
+    /// a trio and six splits/corners.
+    VoisinsDuZero,
+
+    /// "Third of the wheel": the 12 numbers on the arc opposite zero, covered by 6 splits.
+    TiersDuCylindre,
+
+    /// "Orphans": the 8 numbers left over between Voisins and Tiers, covered by a
+    /// straight-up and 4 splits.
+    Orphelins,
+
+    /// `width` pockets either side of `center` on the physical wheel, as straight-ups.
+    Neighbours { center: u8, width: u8 },
+}
+
+impl CallBet {
+    /// Expands this announced bet into the individual bets a player places at the table.
+    pub fn to_bets(&self) -> Vec<RouletteBetType> {
+        match self {
+            CallBet::VoisinsDuZero => vec![
+                RouletteBetType::Basket([0, 2, 3]),
+                RouletteBetType::Split([4, 7]),
+                RouletteBetType::Split([12, 15]),
+                RouletteBetType::Split([18, 21]),
+                RouletteBetType::Split([19, 22]),
+                RouletteBetType::Corner([25, 26, 28, 29]),
+                RouletteBetType::Split([32, 35]),
+            ],
+            CallBet::TiersDuCylindre => vec![
+                RouletteBetType::Split([5, 8]),
+                RouletteBetType::Split([10, 11]),
+                RouletteBetType::Split([13, 16]),
+                RouletteBetType::Split([23, 24]),
+                RouletteBetType::Split([27, 30]),
+                RouletteBetType::Split([33, 36]),
+            ],
+            CallBet::Orphelins => vec![
+                RouletteBetType::Straight(1),
+                RouletteBetType::Split([6, 9]),
+                RouletteBetType::Split([14, 17]),
+                RouletteBetType::Split([17, 20]),
+                RouletteBetType::Split([31, 34]),
+            ],
+            CallBet::Neighbours { center, width } => vec![
+                RouletteBetType::Neighbors { center: *center, each_side: *width },
+            ],
+        }
+    }
+}
+
+/// Formats a pocket number for display, turning the `DOUBLE_ZERO` sentinel into "00".
+pub fn format_number(number: u8) -> String {
+    if number == DOUBLE_ZERO {
+        "00".to_string()
+    } else {
+        number.to_string()
+    }
+}
+
+/// The outcome of a `spin_provably_fair` draw: the winning pocket plus the raw HMAC
+/// digest it was derived from, so a player can independently re-derive it once the
+/// server seed is revealed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProvablyFairSpin {
+    pub number: u8,
+    pub hash: [u8; 32],
+}
+
+/// Derives a deterministic, verifiable winning number from a server seed, a client seed,
+/// and a nonce, instead of drawing from an RNG. The same three inputs always yield the
+/// same pocket, so a revealed server seed lets a player replay every historical spin.
+///
+/// Computes `HMAC-SHA256(key = server_seed, msg = "{client_seed}:{nonce}")`, takes the
+/// first 4 bytes of the digest as a big-endian `u32`, divides by 2^32 to get a float in
+/// `[0, 1)`, then maps it onto a pocket via `floor(float * wheel.pocket_count())`.
+pub fn spin_provably_fair(server_seed: &str, client_seed: &str, nonce: u64, wheel: WheelKind) -> ProvablyFairSpin {
+    let mut mac = HmacSha256::new_varkey(server_seed.as_bytes()).expect("HMAC accepts a key of any length");
+    mac.input(format!("{}:{}", client_seed, nonce).as_bytes());
+
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(&mac.result().code());
+
+    let mut leading_bytes = [0u8; 4];
+    leading_bytes.copy_from_slice(&hash[0..4]);
+    let fraction = u32::from_be_bytes(leading_bytes) as f64 / (u32::max_value() as f64 + 1.0);
+
+    let number = (fraction * wheel.pocket_count() as f64).floor() as u8;
+    ProvablyFairSpin { number, hash }
+}
 
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PlaceBetError {
     InvalidBetOption(RouletteBet),
     MaxBetOnOption(RouletteBet, u64),
     MinBetNotSatisfied(RouletteBet, u64),
+    /// The player's account balance can't cover the total staked across all bets.
+    InsufficientFunds(u64, u64),
+    /// The total staked across all bets exceeds the table's overall stake cap.
+    TableLimitExceeded(u64, u64),
 }
 
 impl fmt::Display for PlaceBetError {
@@ -14,14 +254,103 @@ impl fmt::Display for PlaceBetError {
             PlaceBetError::InvalidBetOption(option) => write!(f, "Invalid Bet Option: {}", option),
             PlaceBetError::MaxBetOnOption(option, max) => write!(f, "Max bet of {} reached on option {}", max, option),
             PlaceBetError::MinBetNotSatisfied(option, min) => write!(f, "Minimum ({}) not met for option {}", min, option),
+            PlaceBetError::InsufficientFunds(required, available) => write!(f, "Insufficient funds: need {} but only {} available", required, available),
+            PlaceBetError::TableLimitExceeded(staked, limit) => write!(f, "Total stake of {} exceeds the table limit of {}", staked, limit),
+        }
+    }
+}
+
+/// Why a `RouletteBetType` failed validation, so a caller gets a precise reason instead
+/// of a bare `false` out of `Roulette::validate_bet_option`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BetError {
+    /// A single number is outside the wheel's valid range.
+    NumberOutOfRange { got: u8, max: u8 },
+    /// The two numbers in a `Split` aren't adjacent on the betting grid.
+    NonAdjacentSplit([u8; 2]),
+    /// The three numbers in a `Street` don't form a row starting at the first column.
+    NotAStreet([u8; 3]),
+    /// The three numbers in a `Basket` don't match either zero-basket layout.
+    InvalidBasket([u8; 3]),
+    /// The four numbers in a `Topline` aren't exactly 0, 1, 2, 3.
+    InvalidTopline([u8; 4]),
+    /// The five numbers in a `FirstFive` aren't 0, 1, 2, 3, 00, or the wheel isn't American.
+    InvalidFirstFive([u8; 5]),
+    /// The four numbers in a `Corner` don't form a 2x2 block on the betting grid.
+    InvalidCorner([u8; 4]),
+    /// The six numbers in a `Doubleline` don't form two adjacent streets.
+    InvalidDoubleline([u8; 6]),
+    /// A selector field (dozen, column, even/odd, high/low, red/black) is out of its valid range.
+    SelectorOutOfRange { field: &'static str, got: u8 },
+    /// A `Neighbors` bet's center isn't a real pocket on this wheel, or its spread wraps
+    /// around and covers a pocket twice.
+    InvalidNeighbors { center: u8, each_side: u8 },
+}
+
+impl fmt::Display for BetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BetError::NumberOutOfRange { got, max } => write!(f, "{} is out of range (max {})", format_number(*got), format_number(*max)),
+            BetError::NonAdjacentSplit(v) => write!(f, "{} and {} are not adjacent on the betting grid", v[0], v[1]),
+            BetError::NotAStreet(v) => write!(f, "{:?} is not a valid street", v),
+            BetError::InvalidBasket(v) => write!(f, "{:?} is not a valid basket", v),
+            BetError::InvalidTopline(v) => write!(f, "{:?} is not a valid topline", v),
+            BetError::InvalidFirstFive(v) => write!(f, "{:?} is not a valid first five", v),
+            BetError::InvalidCorner(v) => write!(f, "{:?} is not a valid corner", v),
+            BetError::InvalidDoubleline(v) => write!(f, "{:?} is not a valid double line", v),
+            BetError::SelectorOutOfRange { field, got } => write!(f, "{} selector {} is out of range", field, got),
+            BetError::InvalidNeighbors { center, each_side } => write!(f, "Neighbors({}, +/-{}) is not valid on this wheel", format_number(*center), each_side),
         }
     }
 }
 
+/// A player's running balance. `Roulette` debits the stake and credits winnings
+/// on every spin so callers don't have to track money themselves.
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Account {
+    balance: u64,
+}
+
+impl Account {
+    pub fn new(balance: u64) -> Self {
+        Self { balance }
+    }
+
+    pub fn balance(&self) -> u64 {
+        self.balance
+    }
+}
+
+/// Running totals for a `Roulette` session, derived as spins are played.
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SessionStats {
+    total_wagered: u64,
+    total_won: u64,
+}
+
+impl SessionStats {
+    pub fn total_wagered(&self) -> u64 {
+        self.total_wagered
+    }
+
+    pub fn total_won(&self) -> u64 {
+        self.total_won
+    }
+
+    /// Net profit (positive) or loss (negative) for the session so far.
+    pub fn net_profit(&self) -> i64 {
+        self.total_won as i64 - self.total_wagered as i64
+    }
+}
+
 /// Bet Types, defined by the type of bet, with the variant always being u8, but in some cases requiring an array of numbers to be inserted.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RouletteBetType {
-    /// Single number for the bet
+    /// Single number for the bet. `DOUBLE_ZERO` (37) represents "00" on an American wheel.
     Straight(u8),
 
     /// Numbers from 2 adjacent spots
@@ -36,17 +365,20 @@ pub enum RouletteBetType {
     /// Numbers covering 0, 1, 2, 3
     Topline([u8; 4]),
 
+    /// American five-number basket: 0, 00, 1, 2, 3. Only valid on an American wheel.
+    FirstFive([u8; 5]),
+
     /// Number of 4 adjacent spots
     Corner([u8; 4]),
 
-    /// Numbers covering 2 adjacent lines 
+    /// Numbers covering 2 adjacent lines
     Doubleline([u8; 6]),
 
     /// 1 for 1-12, 2 for 13-24, 3 for 25-36
     Dozens(u8),
 
     /// Indicate the column based on the lowest number in that column (1, 2 or 3 to match columns under 34,35,36)
-    Columns(u8), 
+    Columns(u8),
 
     /// 0 for even, 1 for odd
     EvenOdd(u8),
@@ -56,17 +388,22 @@ pub enum RouletteBetType {
 
     /// 0 for red, 1 for black
     Redblack(u8),
+
+    /// An announced "neighbours" bet: straight bets on `center` and on `each_side` pockets
+    /// either side of it in the wheel's physical (not numeric) order.
+    Neighbors { center: u8, each_side: u8 },
 }
 
 
 impl fmt::Display for RouletteBetType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            RouletteBetType::Straight(v) => write!(f, "Straight({})", v),
-            RouletteBetType::Split(v) => write!(f, "Split({}, {})", v[0], v[1]),
+            RouletteBetType::Straight(v) => write!(f, "Straight({})", format_number(*v)),
+            RouletteBetType::Split(v) => write!(f, "Split({}, {})", format_number(v[0]), format_number(v[1])),
             RouletteBetType::Street(v) => write!(f, "Street({}, {}, {})", v[0], v[1], v[2]),
             RouletteBetType::Basket(v) => write!(f, "Basket({}, {}, {})", v[0], v[1], v[2]),
             RouletteBetType::Topline(v) => write!(f, "Topline({}, {}, {}, {})", v[0], v[1], v[2], v[3]),
+            RouletteBetType::FirstFive(v) => write!(f, "FirstFive({}, {}, {}, {}, {})", format_number(v[0]), format_number(v[1]), format_number(v[2]), format_number(v[3]), format_number(v[4])),
             RouletteBetType::Corner(v) => write!(f, "Corner({}, {}, {}, {})", v[0], v[1], v[2], v[3]),
             RouletteBetType::Doubleline(v) => write!(f, "Doubleline({}, {}, {}, {}, {}, {})", v[0], v[1], v[2], v[3], v[4], v[5]),
             RouletteBetType::Dozens(v) => write!(f, "Dozens({})", v),
@@ -85,13 +422,15 @@ impl fmt::Display for RouletteBetType {
                 0 => "red",
                 1 => "black",
                 _ => "INVALID",
-            })
+            }),
+            RouletteBetType::Neighbors { center, each_side } => write!(f, "Neighbors({}, +/-{})", format_number(*center), each_side),
         }
     }
 }
 
-/// Definition of a bet. 
+/// Definition of a bet.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RouletteBet {
     bet_type: RouletteBetType,
     wager: u64,
@@ -103,6 +442,114 @@ impl fmt::Display for RouletteBet {
     }
 }
 
+/// The bet-type discriminant, stripped of its number/selector payload, used as the key
+/// into a `PayoutTable`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BetKind {
+    Straight,
+    Split,
+    Street,
+    Basket,
+    Topline,
+    FirstFive,
+    Corner,
+    Doubleline,
+    Dozens,
+    Columns,
+    EvenOdd,
+    Highlow,
+    Redblack,
+    Neighbors,
+}
+
+impl BetKind {
+    /// Whether this bet kind pays out 1:1 and is therefore subject to la partage/en prison.
+    pub fn is_even_money(&self) -> bool {
+        matches!(self, BetKind::EvenOdd | BetKind::Highlow | BetKind::Redblack)
+    }
+}
+
+/// Configurable win multipliers per bet kind, so operators can model payoff variants
+/// other than the standard full-odds table (e.g. reduced odds on `FirstFive`).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PayoutTable {
+    multipliers: std::collections::HashMap<BetKind, u64>,
+}
+
+impl PayoutTable {
+    /// The standard multipliers used by a full-odds European/American table.
+    pub fn standard() -> Self {
+        let mut multipliers = std::collections::HashMap::new();
+        multipliers.insert(BetKind::Straight, 36);
+        multipliers.insert(BetKind::Split, 18);
+        multipliers.insert(BetKind::Street, 12);
+        multipliers.insert(BetKind::Basket, 12);
+        multipliers.insert(BetKind::Topline, 9);
+        multipliers.insert(BetKind::FirstFive, 7);
+        multipliers.insert(BetKind::Corner, 9);
+        multipliers.insert(BetKind::Doubleline, 6);
+        multipliers.insert(BetKind::Dozens, 3);
+        multipliers.insert(BetKind::Columns, 3);
+        multipliers.insert(BetKind::EvenOdd, 2);
+        multipliers.insert(BetKind::Highlow, 2);
+        multipliers.insert(BetKind::Redblack, 2);
+        Self { multipliers }
+    }
+
+    /// The multiplier configured for a bet kind (total return per unit staked, stake included).
+    pub fn multiplier(&self, kind: BetKind) -> u64 {
+        self.multipliers[&kind]
+    }
+
+    pub fn set_multiplier(&mut self, kind: BetKind, multiplier: u64) {
+        self.multipliers.insert(kind, multiplier);
+    }
+}
+
+impl Default for PayoutTable {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+/// Per-bet-kind maximum wagers, analogous to a slot machine's `bet_max`. Kinds with no
+/// entry fall back to `Roulette`'s overall `max_bet_size`.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BetLimits {
+    max_per_kind: std::collections::HashMap<BetKind, u64>,
+}
+
+impl BetLimits {
+    /// No per-kind overrides; every bet kind is capped by `Roulette`'s overall max.
+    pub fn new() -> Self {
+        Self { max_per_kind: std::collections::HashMap::new() }
+    }
+
+    /// The configured max for `kind`, if one was set.
+    pub fn max_for(&self, kind: BetKind) -> Option<u64> {
+        self.max_per_kind.get(&kind).copied()
+    }
+
+    pub fn set_max(&mut self, kind: BetKind, max: u64) {
+        self.max_per_kind.insert(kind, max);
+    }
+}
+
+/// Which even-money house rule, if any, applies when zero (or 00) hits.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EvenMoneyRule {
+    /// Even-money bets simply lose, as on a standard American table.
+    None,
+    /// Half the even-money stake is returned immediately.
+    LaPartage,
+    /// The even-money stake is held ("imprisoned") and resolved on the next spin.
+    EnPrison,
+}
+
 impl RouletteBet {
     pub fn new(bet_type: RouletteBetType, wager: u64) -> Self {
         Self {
@@ -111,22 +558,36 @@ impl RouletteBet {
         }
     }
 
-    /// The win value is the multiplier. In other words if I bet on Even a bet of 10, i get 20. 
-    pub fn win_value(&self) -> u64 {
-        self.wager * match self.bet_type {
-            RouletteBetType::Straight(_) => 36,
-            RouletteBetType::Split(_) => 18,
-            RouletteBetType::Street(_) => 12,
-            RouletteBetType::Basket(_) => 12,
-            RouletteBetType::Topline(_) => 9,
-            RouletteBetType::Corner(_) => 9,
-            RouletteBetType::Doubleline(_) => 6,
-            RouletteBetType::Dozens(_) => 3,
-            RouletteBetType::Columns(_) => 3,
-            RouletteBetType::EvenOdd(_) => 2,
-            RouletteBetType::Highlow(_) => 2,
-            RouletteBetType::Redblack(_) => 2,
+    pub fn kind(&self) -> BetKind {
+        match self.bet_type {
+            RouletteBetType::Straight(_) => BetKind::Straight,
+            RouletteBetType::Split(_) => BetKind::Split,
+            RouletteBetType::Street(_) => BetKind::Street,
+            RouletteBetType::Basket(_) => BetKind::Basket,
+            RouletteBetType::Topline(_) => BetKind::Topline,
+            RouletteBetType::FirstFive(_) => BetKind::FirstFive,
+            RouletteBetType::Corner(_) => BetKind::Corner,
+            RouletteBetType::Doubleline(_) => BetKind::Doubleline,
+            RouletteBetType::Dozens(_) => BetKind::Dozens,
+            RouletteBetType::Columns(_) => BetKind::Columns,
+            RouletteBetType::EvenOdd(_) => BetKind::EvenOdd,
+            RouletteBetType::Highlow(_) => BetKind::Highlow,
+            RouletteBetType::Redblack(_) => BetKind::Redblack,
+            RouletteBetType::Neighbors { .. } => BetKind::Neighbors,
+        }
+    }
 
+    /// The win value is the multiplier. In other words if I bet on Even a bet of 10, i get 20.
+    /// For a `Neighbors` bet the stake is treated as spread evenly across the numbers it
+    /// covers, so the multiplier is the straight-up odds divided by how many it covers
+    /// (consistent with how `Corner`/`Street`/`Doubleline` already spread a single wager).
+    pub fn win_value(&self, payout_table: &PayoutTable) -> u64 {
+        match self.bet_type {
+            RouletteBetType::Neighbors { each_side, .. } => {
+                let covered = 2 * each_side as u64 + 1;
+                self.wager * payout_table.multiplier(BetKind::Straight) / covered
+            }
+            _ => self.wager * payout_table.multiplier(self.kind()),
         }
     }
 
@@ -140,6 +601,7 @@ impl RouletteBet {
 }
 
 /// The result of a bet. Contains the bet itself and the winning amount. The responsibility of the winning is in the struct RouletteEvaluator
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct RouletteBetResult<'a> {
     bet: &'a RouletteBet,
     win: u64,
@@ -168,16 +630,16 @@ struct RouletteEvaluator;
 impl RouletteEvaluator {
 
     // PR: Wouldn't it be a better idea to shift responsibility of colour in here? I would remove colour as a parameter
-    // to the function and calculate it inside this method. 
-    pub fn calculate_winnings<'a>(winning_number: u8, bets: &'a Vec<RouletteBet>) -> Vec<RouletteBetResult<'a>> {
+    // to the function and calculate it inside this method.
+    pub fn calculate_winnings<'a>(winning_number: u8, bets: &'a Vec<RouletteBet>, payout_table: &PayoutTable, wheel: WheelKind) -> Vec<RouletteBetResult<'a>> {
         let mut results = Vec::new();
 
         let colour = RouletteEvaluator::get_number_colour(winning_number);
 
-        /// Takes a roulette bet and the function for that bet type to evaluate it. 
-        fn calc_win<'a, F>(bet: &'a RouletteBet, f: F) -> RouletteBetResult<'a> where F: FnOnce() -> bool {
+        /// Takes a roulette bet and the function for that bet type to evaluate it.
+        fn calc_win<'a, F>(bet: &'a RouletteBet, payout_table: &PayoutTable, f: F) -> RouletteBetResult<'a> where F: FnOnce() -> bool {
             RouletteBetResult::new(bet, if f() {
-                bet.win_value()
+                bet.win_value(payout_table)
             } else {
                 0
             })
@@ -186,43 +648,47 @@ impl RouletteEvaluator {
         for bet in bets {
             results.push(
                 match bet.bet_type() {
-                    RouletteBetType::Straight(v) => calc_win(bet, || v == winning_number), // Just match the number. 
+                    RouletteBetType::Straight(v) => calc_win(bet, payout_table, || v == winning_number), // Just match the number.
 
                     // Determine if the winning number falls in the chosen dozen (1 for 1-12, 2 for 13-24, 3 for 25-36)
-                    // PR: Wouldn't it be simpler to do (winning_number-1)/12 == v - 1 ? 
-                    // For example, if dozen 3 is chosen and 25 comes up: 25 - 1 / 12 = 3 - 1 // We have a winner 
+                    // PR: Wouldn't it be simpler to do (winning_number-1)/12 == v - 1 ?
+                    // For example, if dozen 3 is chosen and 25 comes up: 25 - 1 / 12 = 3 - 1 // We have a winner
                     // For example, if dozen 1 is chosen and 1 comes up: 1 - 1 / 12 = 1 - 1 // We have a winner
                     // For example, if dozen 1 is chosen and 0 comes up: 0 - 1 / 12 <> 1 - 1 // We have a loser
-                    RouletteBetType::Dozens(v) => calc_win(bet, || (winning_number > 0 && (winning_number-1)/12 == v - 1)), 
-                   
+                    RouletteBetType::Dozens(v) => calc_win(bet, payout_table, || (winning_number > 0 && winning_number <= 36 && (winning_number-1)/12 == v - 1)),
+
 
                     // Indicate the column based on the lowest number in that column (1, 2 or 3 to match columns under 34,35,36)
                     // PR: Wouldn't it be easier if we do: winning_number > 0 && winning_number % 3 = (v % 3)
                     // For example: if column 1 is chosen, and 7 comes up 7 % 3 = 1 % 3
                     // For example: if column 3 is chosen, and 33 comes up 33 % 3 = 3 % 3
-                    RouletteBetType::Columns(v) => calc_win(bet, || (winning_number > 0 && winning_number % 3 == v % 3)),
-                        
-                    // Match modulo 2 of winning number and whether it was even (0) or odd(1) 
-                    // PR: v%2 is superflous. we can just have (winning_number % 2) == v 
-                    RouletteBetType::EvenOdd(v) => calc_win(bet, || (winning_number % 2) == v), 
+                    RouletteBetType::Columns(v) => calc_win(bet, payout_table, || (winning_number > 0 && winning_number <= 36 && winning_number % 3 == v % 3)),
+
+                    // Match modulo 2 of winning number and whether it was even (0) or odd(1)
+                    // PR: v%2 is superflous. we can just have (winning_number % 2) == v
+                    RouletteBetType::EvenOdd(v) => calc_win(bet, payout_table, || winning_number > 0 && winning_number <= 36 && (winning_number % 2) == v),
 
 
-                    // 0 = low, 1 = high. Low is between 1 - 18, high 19 - 36. Zero not included (neither high nor low)
-                    RouletteBetType::Highlow(v) => calc_win(bet, || { 
-                        (v == 0 && winning_number >= 1 && winning_number <= 18) || 
+                    // 0 = low, 1 = high. Low is between 1 - 18, high 19 - 36. Zero (and 00) not included (neither high nor low)
+                    RouletteBetType::Highlow(v) => calc_win(bet, payout_table, || {
+                        (v == 0 && winning_number >= 1 && winning_number <= 18) ||
                         (v == 1 && winning_number >= 19 && winning_number <= 36)
                     }),
 
                     // Just match on colour
-                    RouletteBetType::Redblack(v) => calc_win(bet, || v == colour),
+                    RouletteBetType::Redblack(v) => calc_win(bet, payout_table, || colour != 2 && v == colour),
 
                     // In all the following types we just determine whether the number exists within the input array of chosen numbers
-                    RouletteBetType::Split(v) => calc_win(bet, || v.contains(&winning_number)),
-                    RouletteBetType::Street(v) => calc_win(bet, || v.contains(&winning_number)),
-                    RouletteBetType::Basket(v) => calc_win(bet, || v.contains(&winning_number)),
-                    RouletteBetType::Topline(v) => calc_win(bet, || v.contains(&winning_number)),
-                    RouletteBetType::Corner(v) => calc_win(bet, || v.contains(&winning_number)),
-                    RouletteBetType::Doubleline(v) => calc_win(bet, || v.contains(&winning_number)),
+                    RouletteBetType::Split(v) => calc_win(bet, payout_table, || v.contains(&winning_number)),
+                    RouletteBetType::Street(v) => calc_win(bet, payout_table, || v.contains(&winning_number)),
+                    RouletteBetType::Basket(v) => calc_win(bet, payout_table, || v.contains(&winning_number)),
+                    RouletteBetType::Topline(v) => calc_win(bet, payout_table, || v.contains(&winning_number)),
+                    RouletteBetType::FirstFive(v) => calc_win(bet, payout_table, || v.contains(&winning_number)),
+                    RouletteBetType::Corner(v) => calc_win(bet, payout_table, || v.contains(&winning_number)),
+                    RouletteBetType::Doubleline(v) => calc_win(bet, payout_table, || v.contains(&winning_number)),
+
+                    // Matches if the winning number is one of the numbers swept by the neighbour spread.
+                    RouletteBetType::Neighbors { center, each_side } => calc_win(bet, payout_table, || wheel.neighbors(center, each_side).contains(&winning_number)),
                 }
             )
         }
@@ -230,22 +696,118 @@ impl RouletteEvaluator {
         results
     }
 
+    /// Colour of a pocket: 0 = red, 1 = black, 2 = green (zero or double-zero).
     fn get_number_colour(number: u8) -> u8 {
         match number {
-            // PR: what about zero? I would add zero as another colour, as this can affect badly the redBlack bet type.
-            0 => 2,
+            0 | DOUBLE_ZERO => 2,
             1 | 3 | 5 | 7 | 9 | 12 | 14 | 16 | 18 | 19 | 21 | 23 | 25 | 27 | 30 | 32 | 34 | 36 => 0,
             _ => 1,
         }
     }
 }
 
-/// The roulette engine implementation. All the bet history is stored here. 
+/// The odds and expected value of a single bet, computed without spinning.
+#[derive(Debug, Copy, Clone)]
+pub struct BetAnalysis {
+    pub bet: RouletteBet,
+    pub win_probability: f64,
+    pub expected_net: f64,
+    pub house_edge: f64,
+}
+
+/// Computes win probability, expected value and house edge for a set of bets, on a given
+/// wheel, without ever spinning. Useful for strategy/simulation users who want the odds
+/// up front rather than inferring them from many spins.
+pub struct RouletteAnalyzer;
+
+impl RouletteAnalyzer {
+    pub fn analyze_bet(wheel: WheelKind, bet: &RouletteBet, payout_table: &PayoutTable) -> BetAnalysis {
+        let covered_pockets = Self::covered_pockets(bet.bet_type()) as f64;
+        let total_pockets = wheel.pocket_count() as f64;
+        let win_probability = covered_pockets / total_pockets;
+
+        let wager = bet.wager() as f64;
+        let expected_net = win_probability * bet.win_value(payout_table) as f64 - wager;
+        let house_edge = if wager > 0.0 { -expected_net / wager } else { 0.0 };
+
+        BetAnalysis {
+            bet: *bet,
+            win_probability,
+            expected_net,
+            house_edge,
+        }
+    }
+
+    pub fn analyze(wheel: WheelKind, bets: &Vec<RouletteBet>, payout_table: &PayoutTable) -> Vec<BetAnalysis> {
+        bets.iter().map(|bet| Self::analyze_bet(wheel, bet, payout_table)).collect()
+    }
+
+    /// Expected net value of the whole portfolio of bets combined.
+    pub fn portfolio_expected_net(wheel: WheelKind, bets: &Vec<RouletteBet>, payout_table: &PayoutTable) -> f64 {
+        Self::analyze(wheel, bets, payout_table).iter().map(|analysis| analysis.expected_net).sum()
+    }
+
+    /// Number of pockets a bet type covers, used as the numerator of its win probability.
+    fn covered_pockets(bet_type: RouletteBetType) -> u8 {
+        match bet_type {
+            RouletteBetType::Straight(_) => 1,
+            RouletteBetType::Split(_) => 2,
+            RouletteBetType::Street(_) => 3,
+            RouletteBetType::Basket(_) => 3,
+            RouletteBetType::Topline(_) => 4,
+            RouletteBetType::FirstFive(_) => 5,
+            RouletteBetType::Corner(_) => 4,
+            RouletteBetType::Doubleline(_) => 6,
+            RouletteBetType::Dozens(_) => 12,
+            RouletteBetType::Columns(_) => 12,
+            RouletteBetType::EvenOdd(_) => 18,
+            RouletteBetType::Highlow(_) => 18,
+            RouletteBetType::Redblack(_) => 18,
+            RouletteBetType::Neighbors { each_side, .. } => 2 * each_side as u8 + 1,
+        }
+    }
+}
+
+/// The randomness source driving spins. An enum rather than a generic parameter, in
+/// keeping with how the rest of the engine dispatches on `WheelKind` instead of traits.
+#[derive(Debug, Clone)]
+pub enum RouletteRng {
+    /// The default, non-reproducible source used by `Roulette::new()`.
+    Thread(ThreadRng),
+    /// A seeded, reproducible source for deterministic tests and simulations.
+    Seeded(StdRng),
+}
+
+impl RouletteRng {
+    fn next_pocket(&mut self, pocket_count: u8) -> u8 {
+        match self {
+            RouletteRng::Thread(rng) => rng.gen_range(0, pocket_count),
+            RouletteRng::Seeded(rng) => rng.gen_range(0, pocket_count),
+        }
+    }
+}
+
+fn default_rng() -> RouletteRng {
+    RouletteRng::Thread(thread_rng())
+}
+
+/// The roulette engine implementation. All the bet history is stored here.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Roulette {
     history: Vec<u8>,
     min_bet_size: u64,
-    rng: ThreadRng,
+    max_bet_size: u64,
+    bet_limits: BetLimits,
+    max_total_stake: Option<u64>,
+    wheel: WheelKind,
+    account: Account,
+    stats: SessionStats,
+    payout_table: PayoutTable,
+    even_money_rule: EvenMoneyRule,
+    imprisoned: Vec<RouletteBet>,
+    #[cfg_attr(feature = "serde", serde(skip, default = "default_rng"))]
+    rng: RouletteRng,
 }
 
 impl Roulette {
@@ -253,34 +815,280 @@ impl Roulette {
         Self {
             history: Vec::new(),
             min_bet_size: 1,
-            rng: thread_rng(),
+            max_bet_size: u64::max_value(),
+            bet_limits: BetLimits::new(),
+            max_total_stake: None,
+            wheel: WheelKind::European,
+            account: Account::default(),
+            stats: SessionStats::default(),
+            payout_table: PayoutTable::standard(),
+            even_money_rule: EvenMoneyRule::None,
+            imprisoned: Vec::new(),
+            rng: default_rng(),
+        }
+    }
+
+    /// Builds a roulette engine for an American (double-zero) table.
+    pub fn new_american() -> Self {
+        Self {
+            wheel: WheelKind::American,
+            ..Self::new()
+        }
+    }
+
+    /// Builds a roulette engine whose spins are driven by a seeded, reproducible RNG
+    /// instead of the thread-local one, so simulations and tests can replay a sequence
+    /// of winning numbers.
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            rng: RouletteRng::Seeded(StdRng::seed_from_u64(seed)),
+            ..Self::new()
+        }
+    }
+
+    pub fn wheel(&self) -> WheelKind {
+        self.wheel
+    }
+
+    pub fn payout_table(&self) -> &PayoutTable {
+        &self.payout_table
+    }
+
+    pub fn set_payout_table(&mut self, payout_table: PayoutTable) {
+        self.payout_table = payout_table;
+    }
+
+    pub fn even_money_rule(&self) -> EvenMoneyRule {
+        self.even_money_rule
+    }
+
+    pub fn set_even_money_rule(&mut self, rule: EvenMoneyRule) {
+        self.even_money_rule = rule;
+    }
+
+    pub fn min_bet_size(&self) -> u64 {
+        self.min_bet_size
+    }
+
+    pub fn set_min_bet_size(&mut self, min_bet_size: u64) {
+        self.min_bet_size = min_bet_size;
+    }
+
+    /// The table's overall max bet size, used for any bet kind without a `BetLimits` override.
+    pub fn max_bet_size(&self) -> u64 {
+        self.max_bet_size
+    }
+
+    pub fn set_max_bet_size(&mut self, max_bet_size: u64) {
+        self.max_bet_size = max_bet_size;
+    }
+
+    pub fn bet_limits(&self) -> &BetLimits {
+        &self.bet_limits
+    }
+
+    pub fn set_bet_limits(&mut self, bet_limits: BetLimits) {
+        self.bet_limits = bet_limits;
+    }
+
+    /// The cap on the total staked across all bets in a single spin, if any.
+    pub fn max_total_stake(&self) -> Option<u64> {
+        self.max_total_stake
+    }
+
+    pub fn set_max_total_stake(&mut self, max_total_stake: Option<u64>) {
+        self.max_total_stake = max_total_stake;
+    }
+
+    /// Credits the player's account, e.g. for a cash-in at the start of a session.
+    pub fn deposit(&mut self, amount: u64) {
+        self.account.balance += amount;
+    }
+
+    pub fn balance(&self) -> u64 {
+        self.account.balance()
+    }
+
+    pub fn stats(&self) -> SessionStats {
+        self.stats
+    }
+
+    /// How many times each number has hit so far this session.
+    pub fn hit_counts(&self) -> std::collections::HashMap<u8, u32> {
+        let mut counts = std::collections::HashMap::new();
+        for &number in &self.history {
+            *counts.entry(number).or_insert(0) += 1;
         }
+        counts
     }
 
-    /// The roulette spin. Takes a list of bets in, picks the winning number, and returns the results (and any errors)
-    pub fn spin<'a>(&mut self, bets: &'a Vec<RouletteBet>) -> Result<(u8, Vec<RouletteBetResult<'a>>), Vec<PlaceBetError>> {
+    /// The roulette spin. Takes a list of bets in, validates and debits the total stake from
+    /// the account, picks the winning number, credits back any winnings (including any bets
+    /// resolved from en prison) and returns the net change in balance alongside the new
+    /// balance and the per-bet results (or any errors).
+    pub fn spin<'a>(&mut self, bets: &'a Vec<RouletteBet>) -> Result<(u8, Vec<RouletteBetResult<'a>>, i64, u64), Vec<PlaceBetError>> {
         self.validate_bets(bets)?;
+        let number = self.rng.next_pocket(self.wheel.pocket_count());
+        Ok(self.settle_spin(bets, number))
+    }
+
+    /// A spin whose winning number is derived from a provably-fair HMAC-SHA256 digest
+    /// instead of the engine's own RNG, so operators can let a player verify after the
+    /// fact that the server seed wasn't changed to influence the outcome. The caller is
+    /// responsible for keeping `nonce` unique per spin (e.g. an incrementing counter).
+    pub fn spin_provably_fair<'a>(
+        &mut self,
+        bets: &'a Vec<RouletteBet>,
+        server_seed: &str,
+        client_seed: &str,
+        nonce: u64,
+    ) -> Result<(u8, Vec<RouletteBetResult<'a>>, i64, u64, [u8; 32]), Vec<PlaceBetError>> {
+        self.validate_bets(bets)?;
+        let fair_spin = spin_provably_fair(server_seed, client_seed, nonce, self.wheel);
+        let (number, results, net_change, balance) = self.settle_spin(bets, fair_spin.number);
+        Ok((number, results, net_change, balance, fair_spin.hash))
+    }
+
+    /// Debits/credits the account and updates history and stats for a winning number that
+    /// has already been drawn, shared by `spin` and `spin_provably_fair`.
+    fn settle_spin<'a>(&mut self, bets: &'a Vec<RouletteBet>, number: u8) -> (u8, Vec<RouletteBetResult<'a>>, i64, u64) {
+        let total_staked: u64 = bets.iter().map(|bet| bet.wager()).sum();
+        self.account.balance -= total_staked;
 
-        // spin
-        let number = self.rng.gen_range(0, 36);
         self.history.push(number);
 
-        Ok((number, RouletteEvaluator::calculate_winnings(number, &bets)))
+        let imprisoned_returns = self.resolve_imprisoned_bets(number);
+        self.account.balance += imprisoned_returns;
+
+        let mut results = RouletteEvaluator::calculate_winnings(number, &bets, &self.payout_table, self.wheel);
+        if RouletteEvaluator::get_number_colour(number) == 2 {
+            self.apply_even_money_rule(&mut results);
+        }
+
+        let total_won: u64 = results.iter().map(|res| res.win()).sum();
+        self.account.balance += total_won;
+
+        self.stats.total_wagered += total_staked;
+        self.stats.total_won += total_won + imprisoned_returns;
+
+        let net_change = total_won as i64 + imprisoned_returns as i64 - total_staked as i64;
+        (number, results, net_change, self.account.balance)
+    }
+
+    /// Applies the configured even-money house rule (la partage / en prison) to bets that
+    /// lost to a zero/00 spin, crediting back a partial refund or moving them into `imprisoned`
+    /// to be settled on the following spin.
+    fn apply_even_money_rule<'a>(&mut self, results: &mut Vec<RouletteBetResult<'a>>) {
+        for result in results.iter_mut() {
+            if !result.bet().kind().is_even_money() || result.win() != 0 {
+                continue;
+            }
+
+            match self.even_money_rule {
+                EvenMoneyRule::None => {}
+                EvenMoneyRule::LaPartage => {
+                    result.win = result.bet().wager() / 2;
+                }
+                EvenMoneyRule::EnPrison => {
+                    self.imprisoned.push(*result.bet());
+                }
+            }
+        }
+    }
+
+    /// Settles even-money bets held over from a previous zero/00 spin against this spin's
+    /// winning number, returning the total stake to refund to the account. A bet that loses
+    /// outright forfeits its imprisoned stake; a repeat zero/00 leaves it imprisoned again.
+    fn resolve_imprisoned_bets(&mut self, number: u8) -> u64 {
+        if self.imprisoned.is_empty() {
+            return 0;
+        }
+
+        let colour = RouletteEvaluator::get_number_colour(number);
+        let mut returned = 0;
+        let mut still_imprisoned = Vec::new();
+
+        for bet in self.imprisoned.drain(..) {
+            if colour == 2 {
+                still_imprisoned.push(bet);
+                continue;
+            }
+
+            let won = match bet.bet_type() {
+                RouletteBetType::EvenOdd(v) => (number % 2) == v,
+                RouletteBetType::Highlow(v) => (v == 0 && number >= 1 && number <= 18) || (v == 1 && number >= 19 && number <= 36),
+                RouletteBetType::Redblack(v) => v == colour,
+                _ => false,
+            };
+
+            if won {
+                returned += bet.wager();
+            }
+        }
+
+        self.imprisoned = still_imprisoned;
+        returned
     }
 
     pub fn history(&self) -> &[u8] {
         self.history.as_slice()
     }
 
+    /// Settles a single bet against a winning number using the standard payout table,
+    /// without needing a `Roulette` instance or any session state. Returns
+    /// `stake * (multiplier_to_one(bet) + 1)` on a win, `0` on a loss. A `Neighbors` win is
+    /// `stake * straight_multiplier / covered` (see `RouletteBet::win_value`), which can be
+    /// more than that, since `multiplier_to_one` rounds the spread multiplier down first.
+    pub fn payout(bet: RouletteBetType, stake: u64, winning_number: u8) -> u64 {
+        let wrapped = RouletteBet::new(bet, stake);
+        let bets = vec![wrapped];
+        let results = RouletteEvaluator::calculate_winnings(winning_number, &bets, &PayoutTable::standard(), WheelKind::European);
+        results[0].win()
+    }
+
+    /// The standard multiplier-to-one for a bet type, e.g. 35 for a straight-up or
+    /// 1 for an even-money bet. Derived from `PayoutTable::standard()`.
+    pub fn multiplier_to_one(bet: RouletteBetType) -> u64 {
+        let payout_table = PayoutTable::standard();
+
+        match bet {
+            // A Neighbors bet has no entry of its own in the payout table: its stake is
+            // spread evenly across the numbers it covers, same as `RouletteBet::win_value`.
+            RouletteBetType::Neighbors { each_side, .. } => {
+                let covered = 2 * each_side as u64 + 1;
+                payout_table.multiplier(BetKind::Straight) / covered - 1
+            }
+            _ => payout_table.multiplier(RouletteBet::new(bet, 0).kind()) - 1,
+        }
+    }
+
     fn validate_bets(&self, bets: &Vec<RouletteBet>) -> Result<(), Vec<PlaceBetError>> {
         let mut errors = Vec::new();
 
         // check for errors
         for bet in bets {
-            if !Self::validate_bet_option(bet.bet_type()) {
+            if !Self::validate_bet_option(bet.bet_type(), self.wheel) {
                 errors.push(PlaceBetError::InvalidBetOption(bet.clone()))
-            } else if !self.validate_bet_size(bet) {
-                errors.push(PlaceBetError::MinBetNotSatisfied(bet.clone(), self.min_bet_size * Self::min_bet_for_option(bet.bet_type())))
+            } else {
+                let min = self.min_bet_size * Self::min_bet_for_option(bet.bet_type());
+                let max = self.bet_limits.max_for(bet.kind()).unwrap_or(self.max_bet_size);
+
+                if bet.wager() < min {
+                    errors.push(PlaceBetError::MinBetNotSatisfied(bet.clone(), min))
+                } else if bet.wager() > max {
+                    errors.push(PlaceBetError::MaxBetOnOption(bet.clone(), max))
+                }
+            }
+        }
+
+        if errors.len() == 0 {
+            let total_staked: u64 = bets.iter().map(|bet| bet.wager()).sum();
+            if total_staked > self.account.balance {
+                errors.push(PlaceBetError::InsufficientFunds(total_staked, self.account.balance));
+            } else if let Some(max_total_stake) = self.max_total_stake {
+                if total_staked > max_total_stake {
+                    errors.push(PlaceBetError::TableLimitExceeded(total_staked, max_total_stake));
+                }
             }
         }
 
@@ -298,6 +1106,7 @@ impl Roulette {
             RouletteBetType::Street(_) => 1,
             RouletteBetType::Basket(_) => 1,
             RouletteBetType::Topline(_) => 1,
+            RouletteBetType::FirstFive(_) => 1,
             RouletteBetType::Corner(_) => 1,
             RouletteBetType::Doubleline(_) => 1,
             RouletteBetType::Dozens(_) => 1,
@@ -305,103 +1114,214 @@ impl Roulette {
             RouletteBetType::EvenOdd(_) => 1,
             RouletteBetType::Highlow(_) => 1,
             RouletteBetType::Redblack(_) => 1,
+            RouletteBetType::Neighbors { .. } => 1,
         }
     }
 
-    /// Checks that a ```RouletteBetType``` is valid and can be played
+    /// Checks that a ```RouletteBetType``` is valid and can be played on the given wheel.
+    /// *NOTE*: The logic expects the elements in a &[u8] array of values to be sorted in ascending order
+    fn validate_bet_option(bet_type: RouletteBetType, wheel: WheelKind) -> bool {
+        Self::validate(bet_type, wheel).is_ok()
+    }
+
+    /// Same check as `validate_bet_option`, but reporting *why* a bet was rejected instead
+    /// of a bare `false`, so a UI layer can surface a precise message.
     /// *NOTE*: The logic expects the elements in a &[u8] array of values to be sorted in ascending order
-    fn validate_bet_option(bet_type: RouletteBetType) -> bool {
+    pub fn validate(bet_type: RouletteBetType, wheel: WheelKind) -> Result<(), BetError> {
         match bet_type {
             // Staight numbers are easy: any number (including zero) smaller or equal to 36.
-            RouletteBetType::Straight(v) => v <= 36,
-
+            // 00 (the DOUBLE_ZERO sentinel) is only a valid pocket on an American wheel.
+            RouletteBetType::Straight(v) => {
+                if v <= 36 || (v == DOUBLE_ZERO && wheel == WheelKind::American) {
+                    Ok(())
+                } else {
+                    let max = if wheel == WheelKind::American { DOUBLE_ZERO } else { 36 };
+                    Err(BetError::NumberOutOfRange { got: v, max })
+                }
+            }
 
+            // A split is legal when its two numbers are neighbouring cells on the betting grid.
             RouletteBetType::Split(v) => {
+                if BettingGrid::is_adjacent_split(v[0], v[1]) { Ok(()) } else { Err(BetError::NonAdjacentSplit(v)) }
+            }
 
-                // range and duplicate check
-                (v[0] != v[1] && (v[0] <= 35 && v[1] <= 36) && v[1] > v[0]) 
-                &&
-                // splits with zero can only be combined with 1,2,3
-                (
-                    v[0] == 0 && (v[1] == 1 || v[1] == 2 || v[1] == 3)
-                ) 
-                ||
-                // numbers 1 to 33
-                ((v[0] > 0 && v[0] <= 33) && 
-                    (
-                        // right edge
-                        (v[1] % 3 == 0 && v[1] - v[0] == 1 || v[1] - v[0] == 3) ||
-                        // left edge
-                        (v[0] % 3 == 1 && v[1] - v[0] == 1 || v[1] - v[0] == 3)
-                        
-                    )
-                ) 
-                ||
-                // bottom edge (34, 35, 36)
-                (v[0] >= 34 && v[1] - v[0] == 1)
-            }
-
-            // A street has to always start at the first column, and the other two numbers need to be 1 value apart.
+            // A street is legal when it's a full row of the betting grid.
             RouletteBetType::Street(v) => {
-                v[0] > 0 && 
-                v[0] <= 34 && 
-                (v[0]-1) % 3 == 0 &&
-                v[1] - v[0] == 1 &&
-                v[2] - v[1] == 1
+                if BettingGrid::is_valid_street(v) { Ok(()) } else { Err(BetError::NotAStreet(v)) }
             }
 
             // Numbers covering either 0,1,2 or 0,2,3
             RouletteBetType::Basket(v) => {
-                v[0] == 0 &&
-                ((v[1] == 1 && v[2] == 2) ||
-                (v[1] == 2 && v[2] == 3))
+                let valid = v[0] == 0 &&
+                    ((v[1] == 1 && v[2] == 2) ||
+                    (v[1] == 2 && v[2] == 3));
+
+                if valid { Ok(()) } else { Err(BetError::InvalidBasket(v)) }
             }
 
             // Topline is always exactly 0123
             RouletteBetType::Topline(v) => {
-                v[0] == 0 && v[1] == 1 && v[2] == 2 && v[3] == 3
+                let valid = v[0] == 0 && v[1] == 1 && v[2] == 2 && v[3] == 3;
+                if valid { Ok(()) } else { Err(BetError::InvalidTopline(v)) }
             }
 
-            // Corners: Cannot start with zero, they can only start on 1st, 2nd column, rows should have a difference of 3, columns a difference of 1
-            RouletteBetType::Corner(v) => {
-                v[0] > 0 &&
-                (v[0] % 3 != 0) &&
-                v[1] - v[0] == 1 &&
-                v[3] - v[2] == 1 &&
-                v[3] - v[1] == 3 &&
-                v[2] - v[0] == 3
+            // American five-number basket: 0, 00, 1, 2, 3. Only valid on an American wheel.
+            RouletteBetType::FirstFive(v) => {
+                let valid = wheel == WheelKind::American &&
+                    v == [0, 1, 2, 3, DOUBLE_ZERO];
+
+                if valid { Ok(()) } else { Err(BetError::InvalidFirstFive(v)) }
             }
 
+            // A corner is legal when it's a 2x2 block on the betting grid.
+            RouletteBetType::Corner(v) => {
+                if BettingGrid::is_valid_corner(v) { Ok(()) } else { Err(BetError::InvalidCorner(v)) }
+            }
 
-            // Doublle line is essentially two streets. 
+            // Doublle line is essentially two streets.
             RouletteBetType::Doubleline(v) => {
                 let mut slice1: [u8; 3] = Default::default();
                 let mut slice2: [u8; 3] = Default::default();
                 slice1.copy_from_slice(&v[0..=2]);
                 slice2.copy_from_slice(&v[3..=5]);
-                Self::validate_bet_option(RouletteBetType::Street(slice1)) &&
-                Self::validate_bet_option(RouletteBetType::Street(slice2))
-            },
+
+                let valid = Self::validate(RouletteBetType::Street(slice1), wheel).is_ok() &&
+                    Self::validate(RouletteBetType::Street(slice2), wheel).is_ok() &&
+                    slice2[0] == slice1[0] + 3;
+
+                if valid { Ok(()) } else { Err(BetError::InvalidDoubleline(v)) }
+            }
 
             // Can only have values of 1,2,3
-            RouletteBetType::Dozens(v) => v >= 1 && v <= 3,
-            RouletteBetType::Columns(v) => v >= 1 && v <= 3,
+            RouletteBetType::Dozens(v) => {
+                if v >= 1 && v <= 3 { Ok(()) } else { Err(BetError::SelectorOutOfRange { field: "dozens", got: v }) }
+            }
+            RouletteBetType::Columns(v) => {
+                if v >= 1 && v <= 3 { Ok(()) } else { Err(BetError::SelectorOutOfRange { field: "columns", got: v }) }
+            }
 
             // Can only have values of 0, 1
-            RouletteBetType::EvenOdd(v) => v <= 1,
-            RouletteBetType::Highlow(v) => v <= 1,
-            RouletteBetType::Redblack(v) => v <= 1,
-        }
-    }
+            RouletteBetType::EvenOdd(v) => {
+                if v <= 1 { Ok(()) } else { Err(BetError::SelectorOutOfRange { field: "even_odd", got: v }) }
+            }
+            RouletteBetType::Highlow(v) => {
+                if v <= 1 { Ok(()) } else { Err(BetError::SelectorOutOfRange { field: "highlow", got: v }) }
+            }
+            RouletteBetType::Redblack(v) => {
+                if v <= 1 { Ok(()) } else { Err(BetError::SelectorOutOfRange { field: "redblack", got: v }) }
+            }
+
+            // The center must be a real pocket on this wheel, and the spread can't wrap
+            // around and cover the same pocket twice.
+            RouletteBetType::Neighbors { center, each_side } => {
+                let valid = Self::validate(RouletteBetType::Straight(center), wheel).is_ok() &&
+                    (2 * each_side as usize + 1) <= wheel.pocket_order().len();
 
-    fn validate_bet_size(&self, bet: &RouletteBet) -> bool {
-        Self::min_bet_for_option(bet.bet_type()) & self.min_bet_size <= bet.wager()
+                if valid { Ok(()) } else { Err(BetError::InvalidNeighbors { center, each_side }) }
+            }
+        }
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+/// The outcome of one `RouletteEnv::step`: the pocket that hit, what the bet paid out, the
+/// bankroll after settling, and whether the session is over.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StepResult {
+    pub winning_number: u8,
+    pub payout: f64,
+    pub new_balance: f64,
+    pub busted: bool,
+}
+
+/// A steppable environment for backtesting betting strategies (Martingale and friends) or
+/// training an agent, one bet at a time. Unlike `Roulette`, which books a whole vector of
+/// bets per spin in integer stake units, `RouletteEnv` settles a single floating-point stake
+/// per step so a strategy loop can react to each spin before deciding the next one. The
+/// full stake is debited from the balance, but the payout is computed on the stake rounded
+/// to the nearest whole unit.
+#[derive(Debug, Clone)]
+pub struct RouletteEnv {
+    balance: f64,
+    starting_balance: f64,
+    min_bet: f64,
+    max_bet: f64,
+    wheel: WheelKind,
+    payout_table: PayoutTable,
+    rng: RouletteRng,
+}
+
+impl RouletteEnv {
+    pub fn new(balance: f64, min_bet: f64, max_bet: f64) -> Self {
+        Self {
+            balance,
+            starting_balance: balance,
+            min_bet,
+            max_bet,
+            wheel: WheelKind::European,
+            payout_table: PayoutTable::standard(),
+            rng: default_rng(),
+        }
+    }
+
+    /// Builds an environment driven by a seeded, reproducible RNG, so a strategy's
+    /// backtest can be replayed exactly.
+    pub fn with_seed(balance: f64, min_bet: f64, max_bet: f64, seed: u64) -> Self {
+        Self {
+            rng: RouletteRng::Seeded(StdRng::seed_from_u64(seed)),
+            ..Self::new(balance, min_bet, max_bet)
+        }
+    }
+
+    pub fn balance(&self) -> f64 {
+        self.balance
+    }
+
+    pub fn min_bet(&self) -> f64 {
+        self.min_bet
+    }
+
+    pub fn max_bet(&self) -> f64 {
+        self.max_bet
+    }
+
+    /// Spins the wheel once and settles `stake` against `bet`. Returns `None` instead of
+    /// spinning if `stake` falls outside the table limits or the current bankroll can't
+    /// cover it, so a simulation loop can terminate cleanly rather than going negative.
+    pub fn step(&mut self, bet: RouletteBetType, stake: f64) -> Option<StepResult> {
+        if stake < self.min_bet || stake > self.max_bet || stake > self.balance {
+            return None;
+        }
+
+        if !Roulette::validate_bet_option(bet, self.wheel) {
+            return None;
+        }
+
+        let number = self.rng.next_pocket(self.wheel.pocket_count());
+
+        let wrapped = RouletteBet::new(bet, stake.round() as u64);
+        let wrapped_bets = vec![wrapped];
+        let results = RouletteEvaluator::calculate_winnings(number, &wrapped_bets, &self.payout_table, self.wheel);
+        let payout = results[0].win() as f64;
+
+        self.balance = self.balance - stake + payout;
+
+        Some(StepResult {
+            winning_number: number,
+            payout,
+            new_balance: self.balance,
+            busted: self.balance < self.min_bet,
+        })
+    }
+
+    /// Resets the bankroll back to its starting value, for running another simulation pass.
+    pub fn reset(&mut self) {
+        self.balance = self.starting_balance;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
 
     #[test]
     fn spin_and_history_test() {
@@ -410,7 +1330,7 @@ mod test {
 
         for _ in 0..10 {
             match r.spin(&vec![]) {
-                Ok((num, _results)) => {
+                Ok((num, _results, _net_change, _balance)) => {
                     history.push(num);
                 },
                 Err(_) => panic!("Spin failed for some reason!"),
@@ -427,17 +1347,213 @@ mod test {
         }
     }
 
+    #[test]
+    fn spin_and_history_test_american() {
+        let mut r = Roulette::new_american();
+
+        for _ in 0..50 {
+            match r.spin(&vec![]) {
+                Ok((num, _results, _net_change, _balance)) => {
+                    assert!(num <= DOUBLE_ZERO);
+                },
+                Err(_) => panic!("Spin failed for some reason!"),
+            }
+        }
+    }
+
+    #[test]
+    fn spin_debits_and_credits_account() {
+        let mut r = Roulette::new();
+        r.deposit(100);
+
+        let bets = vec![RouletteBet::new(RouletteBetType::Redblack(0), 10)];
+        let (number, results, net_change, balance) = r.spin(&bets).expect("spin should succeed");
+
+        let win = results[0].win();
+        assert_eq!(net_change, win as i64 - 10);
+        assert_eq!(balance as i64, 100 + net_change);
+        assert_eq!(r.balance(), balance);
+        assert_eq!(r.stats().total_wagered(), 10);
+        assert_eq!(r.stats().total_won(), win);
+        assert_eq!(r.hit_counts().get(&number).cloned().unwrap_or(0), 1);
+    }
+
+    #[test]
+    fn spin_rejects_insufficient_funds() {
+        let mut r = Roulette::new();
+        r.deposit(5);
+
+        let bets = vec![RouletteBet::new(RouletteBetType::Redblack(0), 10)];
+        match r.spin(&bets) {
+            Err(errors) => {
+                assert!(errors.iter().any(|e| matches!(e, PlaceBetError::InsufficientFunds(10, 5))));
+            }
+            Ok(_) => panic!("Expected insufficient funds error"),
+        }
+        assert_eq!(r.balance(), 5);
+    }
+
+    #[test]
+    fn spin_rejects_bet_below_min() {
+        let mut r = Roulette::new();
+        r.deposit(100);
+        r.set_min_bet_size(5);
+
+        let bets = vec![RouletteBet::new(RouletteBetType::Redblack(0), 1)];
+        match r.spin(&bets) {
+            Err(errors) => {
+                assert!(errors.iter().any(|e| matches!(e, PlaceBetError::MinBetNotSatisfied(_, 5))));
+            }
+            Ok(_) => panic!("Expected minimum bet error"),
+        }
+    }
+
+    #[test]
+    fn spin_rejects_bet_above_table_max() {
+        let mut r = Roulette::new();
+        r.deposit(1000);
+        r.set_max_bet_size(50);
+
+        let bets = vec![RouletteBet::new(RouletteBetType::Redblack(0), 100)];
+        match r.spin(&bets) {
+            Err(errors) => {
+                assert!(errors.iter().any(|e| matches!(e, PlaceBetError::MaxBetOnOption(_, 50))));
+            }
+            Ok(_) => panic!("Expected max bet error"),
+        }
+    }
+
+    #[test]
+    fn spin_rejects_bet_above_per_kind_max() {
+        let mut r = Roulette::new();
+        r.deposit(1000);
+
+        let mut limits = BetLimits::new();
+        limits.set_max(BetKind::Straight, 10);
+        r.set_bet_limits(limits);
+
+        let bets = vec![RouletteBet::new(RouletteBetType::Straight(17), 20)];
+        match r.spin(&bets) {
+            Err(errors) => {
+                assert!(errors.iter().any(|e| matches!(e, PlaceBetError::MaxBetOnOption(_, 10))));
+            }
+            Ok(_) => panic!("Expected max bet error"),
+        }
+    }
+
+    #[test]
+    fn spin_rejects_total_stake_above_table_limit() {
+        let mut r = Roulette::new();
+        r.deposit(1000);
+        r.set_max_total_stake(Some(15));
+
+        let bets = vec![
+            RouletteBet::new(RouletteBetType::Redblack(0), 10),
+            RouletteBet::new(RouletteBetType::EvenOdd(0), 10),
+        ];
+        match r.spin(&bets) {
+            Err(errors) => {
+                assert!(errors.iter().any(|e| matches!(e, PlaceBetError::TableLimitExceeded(20, 15))));
+            }
+            Ok(_) => panic!("Expected table limit error"),
+        }
+    }
+
+    #[test]
+    fn la_partage_refunds_half_stake_on_zero() {
+        let mut r = Roulette::new();
+        r.set_even_money_rule(EvenMoneyRule::LaPartage);
+
+        let bet = RouletteBet::new(RouletteBetType::Redblack(0), 10);
+        let mut results = vec![RouletteBetResult::new(&bet, 0)];
+        r.apply_even_money_rule(&mut results);
+
+        assert_eq!(results[0].win(), 5);
+    }
+
+    #[test]
+    fn en_prison_holds_then_resolves_stake() {
+        let mut r = Roulette::new();
+        r.set_even_money_rule(EvenMoneyRule::EnPrison);
+
+        let bet = RouletteBet::new(RouletteBetType::Redblack(0), 10);
+        let mut results = vec![RouletteBetResult::new(&bet, 0)];
+        r.apply_even_money_rule(&mut results);
+
+        // Still imprisoned, nothing credited back yet.
+        assert_eq!(results[0].win(), 0);
+        assert_eq!(r.imprisoned.len(), 1);
+
+        // A repeat zero keeps the bet imprisoned.
+        assert_eq!(r.resolve_imprisoned_bets(0), 0);
+        assert_eq!(r.imprisoned.len(), 1);
+
+        // Red comes up: the stake (not the winnings) is returned.
+        assert_eq!(r.resolve_imprisoned_bets(1), 10);
+        assert!(r.imprisoned.is_empty());
+    }
+
+    #[test]
+    fn analyzer_straight_up_win_probability_and_house_edge() {
+        let payout_table = PayoutTable::standard();
+        let bet = RouletteBet::new(RouletteBetType::Straight(1), 10);
+
+        let european = RouletteAnalyzer::analyze_bet(WheelKind::European, &bet, &payout_table);
+        assert!((european.win_probability - 1.0 / 37.0).abs() < 1e-9);
+        assert!(european.house_edge > 0.0 && european.house_edge < 0.05);
+
+        let american = RouletteAnalyzer::analyze_bet(WheelKind::American, &bet, &payout_table);
+        assert!(american.house_edge > european.house_edge);
+    }
+
+    #[test]
+    fn analyzer_even_money_bet_has_same_edge_as_straight() {
+        let payout_table = PayoutTable::standard();
+        let straight = RouletteBet::new(RouletteBetType::Straight(1), 10);
+        let redblack = RouletteBet::new(RouletteBetType::Redblack(0), 10);
+
+        let straight_analysis = RouletteAnalyzer::analyze_bet(WheelKind::European, &straight, &payout_table);
+        let redblack_analysis = RouletteAnalyzer::analyze_bet(WheelKind::European, &redblack, &payout_table);
+
+        assert!((straight_analysis.house_edge - redblack_analysis.house_edge).abs() < 1e-9);
+        assert!((redblack_analysis.win_probability - 18.0 / 37.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn analyzer_portfolio_expected_net_sums_individual_bets() {
+        let payout_table = PayoutTable::standard();
+        let bets = vec![
+            RouletteBet::new(RouletteBetType::Straight(1), 10),
+            RouletteBet::new(RouletteBetType::Redblack(0), 10),
+        ];
+
+        let analyses = RouletteAnalyzer::analyze(WheelKind::European, &bets, &payout_table);
+        let expected: f64 = analyses.iter().map(|a| a.expected_net).sum();
+
+        assert!((RouletteAnalyzer::portfolio_expected_net(WheelKind::European, &bets, &payout_table) - expected).abs() < 1e-9);
+    }
+
     #[test]
     fn roulettebet_win_value() {
+        let payout_table = PayoutTable::standard();
         for i in 1..100 {
             let rbs = RouletteBet::new(RouletteBetType::Straight(1), i);
             let rbc = RouletteBet::new(RouletteBetType::Corner([2,3,5,6]), i);
 
-            assert_eq!(rbs.win_value(), i*36);
-            assert_eq!(rbc.win_value(), i*9)
+            assert_eq!(rbs.win_value(&payout_table), i*36);
+            assert_eq!(rbc.win_value(&payout_table), i*9)
         }
     }
 
+    #[test]
+    fn payout_table_multipliers_are_configurable() {
+        let mut payout_table = PayoutTable::standard();
+        payout_table.set_multiplier(BetKind::FirstFive, 5);
+
+        let bet = RouletteBet::new(RouletteBetType::FirstFive([0, 1, 2, 3, DOUBLE_ZERO]), 10);
+        assert_eq!(bet.win_value(&payout_table), 50);
+    }
+
     #[test]
     fn rouletteeval_calc_winnings() {
         let wager = 10;
@@ -454,9 +1570,10 @@ mod test {
             RouletteBet::new(RouletteBetType::EvenOdd(0), wager),
             RouletteBet::new(RouletteBetType::Highlow(0), wager),
             RouletteBet::new(RouletteBetType::Redblack(1), wager), // PR: Error here. 0 is red, not black. whilst 2 is red. Fixed this.
+            RouletteBet::new(RouletteBetType::Neighbors { center: 2, each_side: 0 }, wager),
         ];
 
-        let results = RouletteEvaluator::calculate_winnings(2, &bets);
+        let results = RouletteEvaluator::calculate_winnings(2, &bets, &PayoutTable::standard(), WheelKind::European);
         let mut winnings = 0;
 
         for res in results {
@@ -466,31 +1583,105 @@ mod test {
                 RouletteBetType::Street(_) => assert_eq!(res.win(), 120),
                 RouletteBetType::Basket(_) => assert_eq!(res.win(), 120),
                 RouletteBetType::Topline(_) => assert_eq!(res.win(), 90),
+                RouletteBetType::FirstFive(_) => assert_eq!(res.win(), 0),
                 RouletteBetType::Corner(_) => assert_eq!(res.win(), 90),
                 RouletteBetType::Doubleline(_) => assert_eq!(res.win(), 60),
                 RouletteBetType::Dozens(_) => assert_eq!(res.win(), 30),
                 RouletteBetType::Columns(_) => assert_eq!(res.win(), 0),
                 RouletteBetType::EvenOdd(_) => assert_eq!(res.win(), 20),
                 RouletteBetType::Highlow(_) => assert_eq!(res.win(), 20),
-                RouletteBetType::Redblack(_) => assert_eq!(res.win(), 20),                
+                RouletteBetType::Redblack(_) => assert_eq!(res.win(), 20),
+                RouletteBetType::Neighbors { .. } => assert_eq!(res.win(), 360),
             }
             winnings += res.win();
         }
 
-        assert_eq!(winnings, 750);
+        assert_eq!(winnings, 1110);
+    }
+
+    #[test]
+    fn redblack_loses_on_zero_and_double_zero() {
+        let wager = 10;
+        let bets = vec![
+            RouletteBet::new(RouletteBetType::Redblack(0), wager),
+            RouletteBet::new(RouletteBetType::Redblack(1), wager),
+        ];
+
+        for zero in [0u8, DOUBLE_ZERO] {
+            let results = RouletteEvaluator::calculate_winnings(zero, &bets, &PayoutTable::standard(), WheelKind::American);
+            for res in results {
+                assert_eq!(res.win(), 0);
+            }
+        }
+    }
+
+    #[test]
+    fn evenodd_and_highlow_lose_on_zero_and_double_zero() {
+        let wager = 10;
+        let bets = vec![
+            RouletteBet::new(RouletteBetType::EvenOdd(0), wager),
+            RouletteBet::new(RouletteBetType::EvenOdd(1), wager),
+            RouletteBet::new(RouletteBetType::Highlow(0), wager),
+            RouletteBet::new(RouletteBetType::Highlow(1), wager),
+        ];
+
+        for zero in [0u8, DOUBLE_ZERO] {
+            let results = RouletteEvaluator::calculate_winnings(zero, &bets, &PayoutTable::standard(), WheelKind::American);
+            for res in results {
+                assert_eq!(res.win(), 0);
+            }
+        }
+    }
+
+    #[test]
+    fn spin_rejects_double_zero_straight_on_european_wheel() {
+        let mut r = Roulette::new();
+        r.deposit(100);
+
+        let bets = vec![RouletteBet::new(RouletteBetType::Straight(DOUBLE_ZERO), 10)];
+        match r.spin(&bets) {
+            Err(errors) => {
+                assert!(errors.iter().any(|e| matches!(e, PlaceBetError::InvalidBetOption(_))));
+            }
+            Ok(_) => panic!("Expected invalid bet option error"),
+        }
+        // The rejected bet should never have been staked.
+        assert_eq!(r.balance(), 100);
+    }
+
+    #[test]
+    fn spin_accepts_double_zero_straight_on_american_wheel() {
+        let mut r = Roulette::new_american();
+        r.deposit(100);
+
+        let bets = vec![RouletteBet::new(RouletteBetType::Straight(DOUBLE_ZERO), 10)];
+        assert!(r.spin(&bets).is_ok());
     }
 
     #[test]
     fn valid_bettype_straights() {
         for i in 0..37 {
-            assert_eq!(Roulette::validate_bet_option(RouletteBetType::Straight(i)), true);
+            assert_eq!(Roulette::validate_bet_option(RouletteBetType::Straight(i), WheelKind::European), true);
         }
     }
 
     #[test]
     fn invalid_bettype_straights() {
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Straight(37)), false);
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Straight(129)), false);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Straight(37), WheelKind::European), false);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Straight(129), WheelKind::European), false);
+    }
+
+    #[test]
+    fn valid_bettype_straight_double_zero_american_only() {
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Straight(DOUBLE_ZERO), WheelKind::American), true);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Straight(DOUBLE_ZERO), WheelKind::European), false);
+    }
+
+    #[test]
+    fn valid_bettype_first_five_american_only() {
+        let bt = RouletteBetType::FirstFive([0, 1, 2, 3, DOUBLE_ZERO]);
+        assert_eq!(Roulette::validate_bet_option(bt, WheelKind::American), true);
+        assert_eq!(Roulette::validate_bet_option(bt, WheelKind::European), false);
     }
 
     #[test]
@@ -498,7 +1689,7 @@ mod test {
         for i in 1..36 {
             if i % 3 != 0 {
                 let bt = RouletteBetType::Split([i, i+1]);
-                let res = Roulette::validate_bet_option(bt);
+                let res = Roulette::validate_bet_option(bt, WheelKind::European);
                 if !res { println!("invalid bettype: {}", bt)}
                 assert_eq!(res, true);
             }
@@ -507,14 +1698,14 @@ mod test {
 
     #[test]
     fn valid_bettype_split_vertical() {
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Split([0, 1])), true);
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Split([0, 2])), true);
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Split([0, 3])), true);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Split([0, 1]), WheelKind::European), true);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Split([0, 2]), WheelKind::European), true);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Split([0, 3]), WheelKind::European), true);
 
         for i in 1..36 {
             if i+3 <= 36 {
                 let bt = RouletteBetType::Split([i, i+3]);
-                let res = Roulette::validate_bet_option(bt);
+                let res = Roulette::validate_bet_option(bt, WheelKind::European);
                 if !res { panic!("invalid bettype: {}", bt)}
                 assert_eq!(res, true);
             }
@@ -524,12 +1715,12 @@ mod test {
     #[test]
     fn invalid_bettype_split() {
         // invalid duplicate split
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Split([1, 1])), false);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Split([1, 1]), WheelKind::European), false);
 
         // invalid 0 splits
         for i in 4..37 {
             let bt = RouletteBetType::Split([0, i]);
-            let res = Roulette::validate_bet_option(bt);
+            let res = Roulette::validate_bet_option(bt, WheelKind::European);
             if res { panic!("Unexpected valid bettype: {}", bt)}
             assert_eq!(res, false);
         }
@@ -537,11 +1728,11 @@ mod test {
         // all other invalid splits
         for i in 1..37 {
             let bt = RouletteBetType::Split([i, i+2]);
-            assert_eq!(Roulette::validate_bet_option(bt), false);
-            
+            assert_eq!(Roulette::validate_bet_option(bt, WheelKind::European), false);
+
             for j in 4..37 {
                 let bt = RouletteBetType::Split([i, i+j]);
-                assert_eq!(Roulette::validate_bet_option(bt), false);
+                assert_eq!(Roulette::validate_bet_option(bt, WheelKind::European), false);
             }
         }
     }
@@ -550,7 +1741,7 @@ mod test {
     fn valid_bettype_street() {
         for i in 1..35 {
             if i%3 == 1 {
-                assert_eq!(Roulette::validate_bet_option(RouletteBetType::Street([i, i+1, i+2])), true);
+                assert_eq!(Roulette::validate_bet_option(RouletteBetType::Street([i, i+1, i+2]), WheelKind::European), true);
             }
         }
     }
@@ -559,43 +1750,43 @@ mod test {
     fn invalid_bettype_street() {
         for i in 1..35 {
             if i%3 != 1 {
-                assert_eq!(Roulette::validate_bet_option(RouletteBetType::Street([i, i+1, i+2])), false);
+                assert_eq!(Roulette::validate_bet_option(RouletteBetType::Street([i, i+1, i+2]), WheelKind::European), false);
             }
         }
     }
 
     #[test]
     fn valid_bettype_basket() {
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Basket([0, 1, 2])), true);
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Basket([0, 2, 3])), true);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Basket([0, 1, 2]), WheelKind::European), true);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Basket([0, 2, 3]), WheelKind::European), true);
     }
 
     #[test]
     fn invalid_bettype_basket() {
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Basket([0, 1, 3])), false);
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Basket([0, 1, 4])), false);
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Basket([1, 2, 3])), false);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Basket([0, 1, 3]), WheelKind::European), false);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Basket([0, 1, 4]), WheelKind::European), false);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Basket([1, 2, 3]), WheelKind::European), false);
 
     }
 
     #[test]
     fn valid_bettype_topline() {
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Topline([0, 1, 2, 3])), true);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Topline([0, 1, 2, 3]), WheelKind::European), true);
     }
 
     #[test]
     fn invalid_bettype_topline() {
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Topline([1, 2, 3, 4])), false);
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Topline([0, 2, 3, 4])), false);
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Topline([0, 2, 3, 5])), false);
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Topline([0, 2, 3, 1])), false);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Topline([1, 2, 3, 4]), WheelKind::European), false);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Topline([0, 2, 3, 4]), WheelKind::European), false);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Topline([0, 2, 3, 5]), WheelKind::European), false);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Topline([0, 2, 3, 1]), WheelKind::European), false);
     }
 
     #[test]
     fn valid_bettype_corner() {
         for i in 1..33 {
             if i % 3 != 0 {
-                assert_eq!(Roulette::validate_bet_option(RouletteBetType::Corner([i, i+1, i+3, i+4])), true);
+                assert_eq!(Roulette::validate_bet_option(RouletteBetType::Corner([i, i+1, i+3, i+4]), WheelKind::European), true);
             }
         }
     }
@@ -604,9 +1795,9 @@ mod test {
     fn invalid_bettype_corner() {
         for i in 1..33 {
             if i % 3 == 0 {
-                assert_eq!(Roulette::validate_bet_option(RouletteBetType::Corner([i, i+1, i+3, i+4])), false);
+                assert_eq!(Roulette::validate_bet_option(RouletteBetType::Corner([i, i+1, i+3, i+4]), WheelKind::European), false);
             } else {
-                assert_eq!(Roulette::validate_bet_option(RouletteBetType::Corner([i, i+1, i+2, i+3])), false);
+                assert_eq!(Roulette::validate_bet_option(RouletteBetType::Corner([i, i+1, i+2, i+3]), WheelKind::European), false);
             }
         }
     }
@@ -615,7 +1806,7 @@ mod test {
     fn valid_bettype_doubleline() {
         for i in 1..32 {
             if i % 3 == 1 {
-                assert_eq!(Roulette::validate_bet_option(RouletteBetType::Doubleline([i, i+1, i+2, i+3, i+4, i+5])), true);
+                assert_eq!(Roulette::validate_bet_option(RouletteBetType::Doubleline([i, i+1, i+2, i+3, i+4, i+5]), WheelKind::European), true);
             }
         }
     }
@@ -624,7 +1815,7 @@ mod test {
     fn invalid_bettype_doubleline() {
         for i in 1..37 {
             if i % 3 != 1 {
-                assert_eq!(Roulette::validate_bet_option(RouletteBetType::Doubleline([i, i+1, i+2, i+3, i+4, i+5])), false);
+                assert_eq!(Roulette::validate_bet_option(RouletteBetType::Doubleline([i, i+1, i+2, i+3, i+4, i+5]), WheelKind::European), false);
             }
         }
     }
@@ -632,65 +1823,65 @@ mod test {
     #[test]
     fn valid_bettype_dozens() {
         for i in 1..4 {
-            assert_eq!(Roulette::validate_bet_option(RouletteBetType::Dozens(i)), true);
+            assert_eq!(Roulette::validate_bet_option(RouletteBetType::Dozens(i), WheelKind::European), true);
         }
     }
 
     #[test]
     fn invalid_bettype_dozens() {
         for i in 4..37 {
-            assert_eq!(Roulette::validate_bet_option(RouletteBetType::Dozens(i)), false);
+            assert_eq!(Roulette::validate_bet_option(RouletteBetType::Dozens(i), WheelKind::European), false);
         }
     }
 
     #[test]
     fn valid_bettype_columns() {
         for i in 4..37 {
-            assert_eq!(Roulette::validate_bet_option(RouletteBetType::Dozens(i)), false);
+            assert_eq!(Roulette::validate_bet_option(RouletteBetType::Dozens(i), WheelKind::European), false);
         }
     }
 
     #[test]
     fn invalid_bettype_columns() {
         for i in 4..37 {
-            assert_eq!(Roulette::validate_bet_option(RouletteBetType::Dozens(i)), false);
+            assert_eq!(Roulette::validate_bet_option(RouletteBetType::Columns(i), WheelKind::European), false);
         }
     }
 
     #[test]
     fn valid_bettype_oddeven() {
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::EvenOdd(0)), true);
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::EvenOdd(1)), true);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::EvenOdd(0), WheelKind::European), true);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::EvenOdd(1), WheelKind::European), true);
     }
 
     #[test]
     fn invalid_bettype_oddeven() {
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::EvenOdd(2)), false);
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::EvenOdd(3)), false);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::EvenOdd(2), WheelKind::European), false);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::EvenOdd(3), WheelKind::European), false);
     }
 
     #[test]
     fn valid_bettype_highlow() {
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Highlow(0)), true);
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Highlow(1)), true);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Highlow(0), WheelKind::European), true);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Highlow(1), WheelKind::European), true);
     }
 
     #[test]
     fn invalid_bettype_highlow() {
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Highlow(2)), false);
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Highlow(3)), false);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Highlow(2), WheelKind::European), false);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Highlow(3), WheelKind::European), false);
     }
 
     #[test]
     fn valid_bettype_redblack() {
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Redblack(0)), true);
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Redblack(1)), true);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Redblack(0), WheelKind::European), true);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Redblack(1), WheelKind::European), true);
     }
 
     #[test]
     fn invalid_bettype_redblack() {
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Redblack(2)), false);
-        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Redblack(3)), false);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Redblack(2), WheelKind::European), false);
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Redblack(3), WheelKind::European), false);
     }
 
     #[test]
@@ -706,7 +1897,361 @@ mod test {
         ];
 
         for bet in vdz {
-            assert_eq!(Roulette::validate_bet_option(bet), true);
+            assert_eq!(Roulette::validate_bet_option(bet, WheelKind::European), true);
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn wheel_order_contains_every_pocket_exactly_once() {
+        let mut european = EUROPEAN_WHEEL_ORDER.to_vec();
+        european.sort();
+        assert_eq!(european, (0..=36).collect::<Vec<u8>>());
+
+        let mut american = AMERICAN_WHEEL_ORDER.to_vec();
+        american.sort();
+        let mut expected: Vec<u8> = (0..=36).collect();
+        expected.push(DOUBLE_ZERO);
+        expected.sort();
+        assert_eq!(american, expected);
+    }
+
+    #[test]
+    fn neighbors_wraps_around_the_wheel() {
+        // 26 is the last entry of EUROPEAN_WHEEL_ORDER, neighbouring 0 at the start.
+        let numbers = WheelKind::European.neighbors(26, 1);
+        assert!(numbers.contains(&26));
+        assert!(numbers.contains(&0));
+        assert!(numbers.contains(&3));
+        assert_eq!(numbers.len(), 3);
+    }
+
+    #[test]
+    fn neighbors_bet_evaluates_against_wheel_order() {
+        let payout_table = PayoutTable::standard();
+        let bet = RouletteBet::new(RouletteBetType::Neighbors { center: 0, each_side: 2 }, 10);
+        let bets = vec![bet];
+        let covered = WheelKind::European.neighbors(0, 2);
+
+        for number in 0..37u8 {
+            let results = RouletteEvaluator::calculate_winnings(number, &bets, &payout_table, WheelKind::European);
+            let expected_win = if covered.contains(&number) { bet.win_value(&payout_table) } else { 0 };
+            assert_eq!(results[0].win(), expected_win);
+        }
+    }
+
+    #[test]
+    fn valid_bettype_neighbors() {
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Neighbors { center: 0, each_side: 2 }, WheelKind::European), true);
+        // 00 is not a European pocket.
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Neighbors { center: DOUBLE_ZERO, each_side: 1 }, WheelKind::European), false);
+        // Spread wraps around and covers pockets twice.
+        assert_eq!(Roulette::validate_bet_option(RouletteBetType::Neighbors { center: 0, each_side: 20 }, WheelKind::European), false);
+    }
+
+    #[test]
+    fn with_seed_is_reproducible() {
+        let bets = vec![RouletteBet::new(RouletteBetType::Straight(7), 10)];
+
+        let mut first = Roulette::with_seed(42);
+        first.deposit(1000);
+        let (first_number, ..) = first.spin(&bets).unwrap();
+
+        let mut second = Roulette::with_seed(42);
+        second.deposit(1000);
+        let (second_number, ..) = second.spin(&bets).unwrap();
+
+        assert_eq!(first_number, second_number);
+    }
+
+    #[test]
+    fn provably_fair_spin_is_deterministic() {
+        let first = spin_provably_fair("server-seed", "client-seed", 1, WheelKind::European);
+        let second = spin_provably_fair("server-seed", "client-seed", 1, WheelKind::European);
+
+        assert_eq!(first, second);
+        assert!(first.number < WheelKind::European.pocket_count());
+    }
+
+    #[test]
+    fn provably_fair_spin_changes_with_nonce() {
+        let first = spin_provably_fair("server-seed", "client-seed", 1, WheelKind::European);
+        let second = spin_provably_fair("server-seed", "client-seed", 2, WheelKind::European);
+
+        assert_ne!(first.hash, second.hash);
+    }
+
+    #[test]
+    fn provably_fair_spin_respects_wheel_pocket_count() {
+        let spin = spin_provably_fair("server-seed", "client-seed", 1, WheelKind::American);
+        assert!(spin.number < WheelKind::American.pocket_count());
+    }
+
+    #[test]
+    fn roulette_spin_provably_fair_settles_like_a_normal_spin() {
+        let mut r = Roulette::new();
+        r.deposit(1000);
+
+        let bets = vec![RouletteBet::new(RouletteBetType::Straight(7), 10)];
+        let (number, results, net_change, balance, hash) = r.spin_provably_fair(&bets, "server-seed", "client-seed", 1).unwrap();
+
+        let expected = spin_provably_fair("server-seed", "client-seed", 1, WheelKind::European);
+        assert_eq!(number, expected.number);
+        assert_eq!(hash, expected.hash);
+
+        let win = results[0].win();
+        assert_eq!(net_change, win as i64 - 10);
+        assert_eq!(balance as i64, 1000 + net_change);
+        assert_eq!(r.history(), &[number]);
+    }
+
+    #[test]
+    fn named_sectors_cover_the_expected_numbers() {
+        assert_eq!(VOISINS_DU_ZERO.len(), 17);
+        assert_eq!(TIERS_DU_CYLINDRE.len(), 12);
+        assert_eq!(ORPHELINS.len(), 8);
+    }
+
+    #[test]
+    fn payout_pays_standard_ratios_on_a_win() {
+        assert_eq!(Roulette::payout(RouletteBetType::Straight(17), 10, 17), 10 * 36);
+        assert_eq!(Roulette::payout(RouletteBetType::Split([17, 20]), 10, 17), 10 * 18);
+        assert_eq!(Roulette::payout(RouletteBetType::Street([1, 2, 3]), 10, 2), 10 * 12);
+        assert_eq!(Roulette::payout(RouletteBetType::Basket([0, 1, 2]), 10, 0), 10 * 12);
+        assert_eq!(Roulette::payout(RouletteBetType::Corner([1, 2, 4, 5]), 10, 4), 10 * 9);
+        assert_eq!(Roulette::payout(RouletteBetType::Doubleline([1, 2, 3, 4, 5, 6]), 10, 5), 10 * 6);
+        assert_eq!(Roulette::payout(RouletteBetType::Dozens(1), 10, 7), 10 * 3);
+        assert_eq!(Roulette::payout(RouletteBetType::Columns(1), 10, 7), 10 * 3);
+        assert_eq!(Roulette::payout(RouletteBetType::EvenOdd(0), 10, 8), 10 * 2);
+        assert_eq!(Roulette::payout(RouletteBetType::Highlow(0), 10, 8), 10 * 2);
+        assert_eq!(Roulette::payout(RouletteBetType::Redblack(1), 10, 8), 10 * 2);
+    }
+
+    #[test]
+    fn payout_returns_zero_on_a_loss() {
+        assert_eq!(Roulette::payout(RouletteBetType::Straight(17), 10, 18), 0);
+        assert_eq!(Roulette::payout(RouletteBetType::Dozens(1), 10, 13), 0);
+        assert_eq!(Roulette::payout(RouletteBetType::Redblack(1), 10, 0), 0);
+    }
+
+    #[test]
+    fn multiplier_to_one_matches_standard_ratios() {
+        assert_eq!(Roulette::multiplier_to_one(RouletteBetType::Straight(0)), 35);
+        assert_eq!(Roulette::multiplier_to_one(RouletteBetType::Split([0, 1])), 17);
+        assert_eq!(Roulette::multiplier_to_one(RouletteBetType::Street([1, 2, 3])), 11);
+        assert_eq!(Roulette::multiplier_to_one(RouletteBetType::Basket([0, 1, 2])), 11);
+        assert_eq!(Roulette::multiplier_to_one(RouletteBetType::Corner([1, 2, 4, 5])), 8);
+        assert_eq!(Roulette::multiplier_to_one(RouletteBetType::Doubleline([1, 2, 3, 4, 5, 6])), 5);
+        assert_eq!(Roulette::multiplier_to_one(RouletteBetType::Dozens(1)), 2);
+        assert_eq!(Roulette::multiplier_to_one(RouletteBetType::Columns(1)), 2);
+        assert_eq!(Roulette::multiplier_to_one(RouletteBetType::EvenOdd(0)), 1);
+        assert_eq!(Roulette::multiplier_to_one(RouletteBetType::Highlow(0)), 1);
+        assert_eq!(Roulette::multiplier_to_one(RouletteBetType::Redblack(0)), 1);
+    }
+
+    #[test]
+    fn multiplier_to_one_spreads_neighbors_like_win_value() {
+        assert_eq!(Roulette::multiplier_to_one(RouletteBetType::Neighbors { center: 0, each_side: 0 }), 35);
+        assert_eq!(Roulette::multiplier_to_one(RouletteBetType::Neighbors { center: 0, each_side: 2 }), 6);
+    }
+
+    #[test]
+    fn validate_reports_number_out_of_range() {
+        assert_eq!(
+            Roulette::validate(RouletteBetType::Straight(37), WheelKind::European),
+            Err(BetError::NumberOutOfRange { got: 37, max: 36 })
+        );
+        assert_eq!(
+            Roulette::validate(RouletteBetType::Straight(38), WheelKind::American),
+            Err(BetError::NumberOutOfRange { got: 38, max: DOUBLE_ZERO })
+        );
+        assert_eq!(Roulette::validate(RouletteBetType::Straight(17), WheelKind::European), Ok(()));
+    }
+
+    #[test]
+    fn validate_reports_non_adjacent_split() {
+        assert_eq!(
+            Roulette::validate(RouletteBetType::Split([1, 1]), WheelKind::European),
+            Err(BetError::NonAdjacentSplit([1, 1]))
+        );
+        assert_eq!(Roulette::validate(RouletteBetType::Split([1, 2]), WheelKind::European), Ok(()));
+    }
+
+    #[test]
+    fn validate_reports_not_a_street() {
+        assert_eq!(
+            Roulette::validate(RouletteBetType::Street([2, 3, 4]), WheelKind::European),
+            Err(BetError::NotAStreet([2, 3, 4]))
+        );
+        assert_eq!(Roulette::validate(RouletteBetType::Street([1, 2, 3]), WheelKind::European), Ok(()));
+    }
+
+    #[test]
+    fn validate_reports_invalid_corner() {
+        assert_eq!(
+            Roulette::validate(RouletteBetType::Corner([1, 2, 3, 4]), WheelKind::European),
+            Err(BetError::InvalidCorner([1, 2, 3, 4]))
+        );
+        assert_eq!(Roulette::validate(RouletteBetType::Corner([1, 2, 4, 5]), WheelKind::European), Ok(()));
+    }
+
+    #[test]
+    fn betting_grid_adjacent_split() {
+        assert_eq!(BettingGrid::is_adjacent_split(1, 2), true);
+        assert_eq!(BettingGrid::is_adjacent_split(1, 4), true);
+        assert_eq!(BettingGrid::is_adjacent_split(3, 4), false);
+        assert_eq!(BettingGrid::is_adjacent_split(33, 36), true);
+        assert_eq!(BettingGrid::is_adjacent_split(1, 1), false);
+    }
+
+    #[test]
+    fn betting_grid_adjacent_split_with_zero() {
+        assert_eq!(BettingGrid::is_adjacent_split(0, 1), true);
+        assert_eq!(BettingGrid::is_adjacent_split(0, 2), true);
+        assert_eq!(BettingGrid::is_adjacent_split(0, 3), true);
+        assert_eq!(BettingGrid::is_adjacent_split(0, 4), false);
+    }
+
+    #[test]
+    fn betting_grid_valid_corner() {
+        for i in 1..33 {
+            if i % 3 != 0 {
+                assert_eq!(BettingGrid::is_valid_corner([i, i + 1, i + 3, i + 4]), true);
+            }
+        }
+    }
+
+    #[test]
+    fn betting_grid_rejects_corner_past_last_row() {
+        // 34 is not in the rightmost column but has no row below it on the grid.
+        assert_eq!(BettingGrid::is_valid_corner([34, 35, 37, 38]), false);
+    }
+
+    #[test]
+    fn betting_grid_valid_street() {
+        for i in (1..35).step_by(3) {
+            assert_eq!(BettingGrid::is_valid_street([i, i + 1, i + 2]), true);
+        }
+    }
+
+    #[test]
+    fn betting_grid_rejects_non_row_street() {
+        assert_eq!(BettingGrid::is_valid_street([2, 3, 4]), false);
+        assert_eq!(BettingGrid::is_valid_street([0, 1, 2]), false);
+    }
+
+    #[test]
+    fn validate_reports_selector_out_of_range() {
+        assert_eq!(
+            Roulette::validate(RouletteBetType::EvenOdd(2), WheelKind::European),
+            Err(BetError::SelectorOutOfRange { field: "even_odd", got: 2 })
+        );
+        assert_eq!(
+            Roulette::validate(RouletteBetType::Highlow(3), WheelKind::European),
+            Err(BetError::SelectorOutOfRange { field: "highlow", got: 3 })
+        );
+        assert_eq!(Roulette::validate(RouletteBetType::EvenOdd(0), WheelKind::European), Ok(()));
+    }
+
+    #[test]
+    fn call_bet_tiers_matches_canonical_splits() {
+        assert_eq!(
+            CallBet::TiersDuCylindre.to_bets(),
+            vec![
+                RouletteBetType::Split([5, 8]),
+                RouletteBetType::Split([10, 11]),
+                RouletteBetType::Split([13, 16]),
+                RouletteBetType::Split([23, 24]),
+                RouletteBetType::Split([27, 30]),
+                RouletteBetType::Split([33, 36]),
+            ]
+        );
+    }
+
+    #[test]
+    fn call_bet_orphelins_matches_canonical_bets() {
+        assert_eq!(
+            CallBet::Orphelins.to_bets(),
+            vec![
+                RouletteBetType::Straight(1),
+                RouletteBetType::Split([6, 9]),
+                RouletteBetType::Split([14, 17]),
+                RouletteBetType::Split([17, 20]),
+                RouletteBetType::Split([31, 34]),
+            ]
+        );
+    }
+
+    #[test]
+    fn call_bet_voisins_covers_the_voisins_number_set() {
+        let mut covered: Vec<u8> = CallBet::VoisinsDuZero
+            .to_bets()
+            .into_iter()
+            .flat_map(|bet| match bet {
+                RouletteBetType::Basket(v) => v.to_vec(),
+                RouletteBetType::Split(v) => v.to_vec(),
+                RouletteBetType::Corner(v) => v.to_vec(),
+                _ => vec![],
+            })
+            .collect();
+        covered.sort();
+        covered.dedup();
+
+        let mut expected = VOISINS_DU_ZERO.to_vec();
+        expected.sort();
+
+        assert_eq!(covered, expected);
+    }
+
+    #[test]
+    fn call_bet_neighbours_wraps_the_neighbors_bet() {
+        assert_eq!(
+            CallBet::Neighbours { center: 0, width: 2 }.to_bets(),
+            vec![RouletteBetType::Neighbors { center: 0, each_side: 2 }]
+        );
+    }
+
+    #[test]
+    fn roulette_env_settles_a_winning_straight_bet() {
+        // Seed 0 on a European wheel happens to land on 0 first; assert against
+        // whatever pocket it actually draws instead of assuming a specific number.
+        let mut env = RouletteEnv::with_seed(100.0, 1.0, 50.0, 0);
+        let result = env.step(RouletteBetType::Straight(0), 10.0).unwrap();
+
+        let expected_payout = if result.winning_number == 0 { 360.0 } else { 0.0 };
+        assert_eq!(result.payout, expected_payout);
+        assert_eq!(result.new_balance, 100.0 - 10.0 + expected_payout);
+        assert_eq!(env.balance(), result.new_balance);
+        assert_eq!(result.busted, false);
+    }
+
+    #[test]
+    fn roulette_env_rejects_stake_outside_table_limits() {
+        let mut env = RouletteEnv::with_seed(100.0, 5.0, 20.0, 1);
+        assert_eq!(env.step(RouletteBetType::Redblack(0), 1.0), None);
+        assert_eq!(env.step(RouletteBetType::Redblack(0), 25.0), None);
+        assert_eq!(env.balance(), 100.0);
+    }
+
+    #[test]
+    fn roulette_env_rejects_invalid_bet_option() {
+        // DOUBLE_ZERO only exists on an American wheel; this env defaults to European.
+        let mut env = RouletteEnv::with_seed(100.0, 1.0, 50.0, 4);
+        assert_eq!(env.step(RouletteBetType::Straight(DOUBLE_ZERO), 10.0), None);
+        assert_eq!(env.balance(), 100.0);
+    }
+
+    #[test]
+    fn roulette_env_rejects_stake_above_balance() {
+        let mut env = RouletteEnv::with_seed(10.0, 1.0, 50.0, 2);
+        assert_eq!(env.step(RouletteBetType::Redblack(0), 20.0), None);
+        assert_eq!(env.balance(), 10.0);
+    }
+
+    #[test]
+    fn roulette_env_reset_restores_starting_balance() {
+        let mut env = RouletteEnv::with_seed(100.0, 1.0, 50.0, 3);
+        env.step(RouletteBetType::Redblack(0), 10.0);
+        env.reset();
+        assert_eq!(env.balance(), 100.0);
+    }
+}